@@ -0,0 +1,22 @@
+use super::destructor::{self, Destructor};
+use crate::ffi::*;
+
+pub struct Context {
+	pub(crate) ptr: *mut AVFormatContext,
+	#[allow(dead_code)]
+	dtor: Destructor,
+}
+
+impl Context {
+	pub unsafe fn wrap(ptr: *mut AVFormatContext, mode: destructor::Mode) -> Self {
+		Context { ptr, dtor: Destructor::new(ptr, mode) }
+	}
+
+	pub unsafe fn as_ptr(&self) -> *const AVFormatContext {
+		self.ptr
+	}
+
+	pub unsafe fn as_mut_ptr(&mut self) -> *mut AVFormatContext {
+		self.ptr
+	}
+}
@@ -0,0 +1,37 @@
+use crate::ffi::*;
+
+pub enum Mode {
+	Input,
+	Output,
+}
+
+pub struct Destructor {
+	ptr: *mut AVFormatContext,
+	mode: Mode,
+}
+
+impl Destructor {
+	pub unsafe fn new(ptr: *mut AVFormatContext, mode: Mode) -> Self {
+		Destructor { ptr, mode }
+	}
+}
+
+impl Drop for Destructor {
+	fn drop(&mut self) {
+		unsafe {
+			match self.mode {
+				Mode::Input => avformat_close_input(&mut self.ptr),
+
+				Mode::Output => {
+					// Custom AVIO (`AVFMT_FLAG_CUSTOM_IO`) is owned by the caller,
+					// so only close `pb` here when FFmpeg allocated it itself.
+					if (*self.ptr).flags & AVFMT_FLAG_CUSTOM_IO == 0 && !(*self.ptr).pb.is_null() {
+						avio_close((*self.ptr).pb);
+					}
+
+					avformat_free_context(self.ptr);
+				}
+			}
+		}
+	}
+}
@@ -0,0 +1,17 @@
+use ffi::*;
+use std::os::raw::c_int;
+
+bitflags! {
+    pub struct SeekFlags: c_int {
+        const BACKWARD = AVSEEK_FLAG_BACKWARD;
+        const BYTE     = AVSEEK_FLAG_BYTE;
+        const ANY      = AVSEEK_FLAG_ANY;
+        const FRAME    = AVSEEK_FLAG_FRAME;
+    }
+}
+
+impl Default for SeekFlags {
+    fn default() -> Self {
+        SeekFlags::BACKWARD
+    }
+}
@@ -0,0 +1,159 @@
+use std::{
+	ops::{Bound, RangeBounds},
+	ptr,
+};
+
+use super::{common::Context, destructor::Mode, flag::SeekFlags};
+use crate::{ffi::*, format::stream::Stream, media, Codec, Error};
+
+pub struct Input {
+	ptr: Context,
+}
+
+impl Input {
+	pub unsafe fn wrap(ptr: *mut AVFormatContext) -> Self {
+		Input { ptr: Context::wrap(ptr, Mode::Input) }
+	}
+
+	pub unsafe fn as_ptr(&self) -> *const AVFormatContext {
+		self.ptr.as_ptr()
+	}
+
+	pub unsafe fn as_mut_ptr(&mut self) -> *mut AVFormatContext {
+		self.ptr.as_mut_ptr()
+	}
+
+	/// Seeks to the keyframe at or before `timestamp` (in `AV_TIME_BASE`
+	/// units), constraining the result to land within `range`.
+	///
+	/// Bounds default to the widest possible range, so `seek(ts, ..)` behaves
+	/// like a plain seek with no lower/upper constraint.
+	pub fn seek(&mut self, timestamp: i64, range: impl RangeBounds<i64>) -> Result<(), Error> {
+		let (min, max) = seek_bounds(range);
+
+		unsafe {
+			match avformat_seek_file(
+				self.as_mut_ptr(),
+				-1,
+				min,
+				timestamp,
+				max,
+				SeekFlags::default().bits(),
+			) {
+				s if s >= 0 => Ok(()),
+				e => Err(Error::from(e)),
+			}
+		}
+	}
+
+	/// Lower-level seek on a single stream, or on the whole container when
+	/// `stream_index` is `-1`. `timestamp` is interpreted in `AV_TIME_BASE`
+	/// units for the whole-container case, or in the stream's own time base
+	/// otherwise.
+	pub fn seek_stream(
+		&mut self,
+		stream_index: i32,
+		timestamp: i64,
+		flags: SeekFlags,
+	) -> Result<(), Error> {
+		unsafe {
+			match av_seek_frame(self.as_mut_ptr(), stream_index, timestamp, flags.bits()) {
+				s if s >= 0 => Ok(()),
+				e => Err(Error::from(e)),
+			}
+		}
+	}
+
+	/// Picks the best stream of the given `kind` using FFmpeg's own scoring
+	/// (bitrate, channel count, disposition/default flags), sparing callers
+	/// from hand-rolling a loop over `streams`.
+	pub fn best_stream(&self, kind: media::Type) -> Option<Stream> {
+		unsafe {
+			let index = av_find_best_stream(
+				self.as_ptr() as *mut _,
+				kind.into(),
+				-1,
+				-1,
+				ptr::null_mut(),
+				0,
+			);
+
+			stream_index(index).map(|index| Stream::wrap(self.as_ptr() as *mut _, index))
+		}
+	}
+
+	/// Like [`Input::best_stream`], but also returns the decoder FFmpeg
+	/// paired with it, sparing callers from looking it up separately.
+	pub fn best_stream_with_decoder(&self, kind: media::Type) -> Option<(Stream, Codec)> {
+		unsafe {
+			let mut decoder = ptr::null_mut();
+			let index = av_find_best_stream(
+				self.as_ptr() as *mut _,
+				kind.into(),
+				-1,
+				-1,
+				&mut decoder,
+				0,
+			);
+
+			if decoder.is_null() {
+				return None;
+			}
+
+			stream_index(index).map(|index| (Stream::wrap(self.as_ptr() as *mut _, index), Codec::wrap(decoder)))
+		}
+	}
+}
+
+/// Translates `av_find_best_stream`'s "negative on not-found" convention into
+/// an `Option`, shared by [`Input::best_stream`] and
+/// [`Input::best_stream_with_decoder`].
+fn stream_index(index: i32) -> Option<usize> {
+	if index < 0 {
+		None
+	} else {
+		Some(index as usize)
+	}
+}
+
+/// Translates a `RangeBounds<i64>` into the inclusive `min`/`max` pair
+/// `avformat_seek_file` expects, split out of [`Input::seek`] so the
+/// `Excluded`-bound off-by-one adjustments can be unit-tested without a real
+/// `AVFormatContext`.
+fn seek_bounds(range: impl RangeBounds<i64>) -> (i64, i64) {
+	let min = match range.start_bound() {
+		Bound::Included(&t) => t,
+		Bound::Excluded(&t) => t + 1,
+		Bound::Unbounded => i64::MIN,
+	};
+
+	let max = match range.end_bound() {
+		Bound::Included(&t) => t,
+		Bound::Excluded(&t) => t - 1,
+		Bound::Unbounded => i64::MAX,
+	};
+
+	(min, max)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn seek_bounds_translates_range_bounds() {
+		assert_eq!(seek_bounds(..), (i64::MIN, i64::MAX));
+		assert_eq!(seek_bounds(10..20), (10, 19));
+		assert_eq!(seek_bounds(10..=20), (10, 20));
+		assert_eq!(seek_bounds(10..), (10, i64::MAX));
+		assert_eq!(seek_bounds(..20), (i64::MIN, 19));
+		assert_eq!(seek_bounds(..=20), (i64::MIN, 20));
+	}
+
+	#[test]
+	fn stream_index_is_none_for_negative_results() {
+		assert_eq!(stream_index(-1), None);
+		assert_eq!(stream_index(0), Some(0));
+		assert_eq!(stream_index(3), Some(3));
+	}
+}
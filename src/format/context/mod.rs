@@ -0,0 +1,12 @@
+pub mod common;
+mod destructor;
+pub mod flag;
+pub mod input;
+pub mod output;
+
+pub use self::{flag::SeekFlags, input::Input, output::Output};
+
+pub enum Context {
+	Input(Input),
+	Output(Output),
+}
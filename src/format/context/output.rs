@@ -0,0 +1,20 @@
+use super::{common::Context, destructor::Mode};
+use crate::ffi::*;
+
+pub struct Output {
+	ptr: Context,
+}
+
+impl Output {
+	pub unsafe fn wrap(ptr: *mut AVFormatContext) -> Self {
+		Output { ptr: Context::wrap(ptr, Mode::Output) }
+	}
+
+	pub unsafe fn as_ptr(&self) -> *const AVFormatContext {
+		self.ptr.as_ptr()
+	}
+
+	pub unsafe fn as_mut_ptr(&mut self) -> *mut AVFormatContext {
+		self.ptr.as_mut_ptr()
+	}
+}
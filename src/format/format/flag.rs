@@ -0,0 +1,16 @@
+use ffi::*;
+use std::os::raw::c_int;
+
+bitflags! {
+    pub struct Flags: c_int {
+        const NO_FILE       = AVFMT_NOFILE;
+        const NEED_NUMBER   = AVFMT_NEEDNUMBER;
+        const GLOBAL_HEADER = AVFMT_GLOBALHEADER;
+        const NO_TIMESTAMPS = AVFMT_NOTIMESTAMPS;
+        const GENERIC_INDEX = AVFMT_GENERIC_INDEX;
+        const TS_DISCONT    = AVFMT_TS_DISCONT;
+        const VARIABLE_FPS  = AVFMT_VARIABLE_FPS;
+        const NO_DIMENSIONS = AVFMT_NODIMENSIONS;
+        const NO_STREAMS    = AVFMT_NOSTREAMS;
+    }
+}
@@ -0,0 +1,94 @@
+use std::{ffi::CStr, ptr, str::from_utf8_unchecked};
+
+use crate::{ffi::*, Format};
+
+pub mod flag;
+pub use self::flag::Flags;
+
+/// A registered demuxer descriptor (`AVInputFormat`), e.g. as returned by
+/// [`super::probe`].
+pub struct Input {
+	ptr: *mut AVInputFormat,
+}
+
+unsafe impl Send for Input {}
+
+impl Input {
+	pub unsafe fn wrap(ptr: *mut AVInputFormat) -> Self {
+		Input { ptr }
+	}
+
+	pub unsafe fn as_ptr(&self) -> *const AVInputFormat {
+		self.ptr as *const _
+	}
+
+	pub fn name(&self) -> &str {
+		unsafe { from_utf8_unchecked(CStr::from_ptr((*self.ptr).name).to_bytes()) }
+	}
+}
+
+/// A registered muxer descriptor (`AVOutputFormat`).
+pub struct Output {
+	ptr: *mut AVOutputFormat,
+}
+
+unsafe impl Send for Output {}
+
+impl Output {
+	pub unsafe fn wrap(ptr: *mut AVOutputFormat) -> Self {
+		Output { ptr }
+	}
+
+	pub unsafe fn as_ptr(&self) -> *const AVOutputFormat {
+		self.ptr as *const _
+	}
+
+	pub fn name(&self) -> &str {
+		unsafe { from_utf8_unchecked(CStr::from_ptr((*self.ptr).name).to_bytes()) }
+	}
+}
+
+pub struct FormatIter {
+	input: *mut AVInputFormat,
+	output: *mut AVOutputFormat,
+}
+
+impl FormatIter {
+	pub fn new() -> Self {
+		FormatIter { input: ptr::null_mut(), output: ptr::null_mut() }
+	}
+}
+
+impl Default for FormatIter {
+	fn default() -> Self {
+		FormatIter::new()
+	}
+}
+
+impl Iterator for FormatIter {
+	type Item = Format;
+
+	fn next(&mut self) -> Option<Format> {
+		unsafe {
+			let input = av_iformat_next(self.input);
+
+			if !input.is_null() {
+				self.input = input;
+				return Some(Format::Input(Input::wrap(input)));
+			}
+
+			let output = av_oformat_next(self.output);
+
+			if !output.is_null() {
+				self.output = output;
+				return Some(Format::Output(Output::wrap(output)));
+			}
+
+			None
+		}
+	}
+}
+
+pub fn list() -> FormatIter {
+	FormatIter::new()
+}
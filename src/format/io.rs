@@ -0,0 +1,405 @@
+use std::{
+	io::{Chain, Cursor, Read, Seek, SeekFrom, Write},
+	ops::{Deref, DerefMut},
+	os::raw::{c_int, c_void},
+	ptr,
+};
+
+use crate::{ffi::*, format::context, Error};
+
+const BUFFER_SIZE: usize = 4096;
+
+/// Owns the `AVIOContext` and scratch buffer backing a custom reader/writer,
+/// and reclaims the boxed Rust value they point at on drop.
+struct AVIO<T> {
+	context: *mut AVIOContext,
+	opaque: *mut T,
+}
+
+impl<T> Drop for AVIO<T> {
+	fn drop(&mut self) {
+		unsafe {
+			if !self.context.is_null() {
+				av_freep(&mut (*self.context).buffer as *mut _ as *mut c_void);
+				avio_context_free(&mut self.context);
+			}
+
+			if !self.opaque.is_null() {
+				drop(Box::from_raw(self.opaque));
+			}
+		}
+	}
+}
+
+/// Allocates the scratch buffer and boxes `value`, handing both to the
+/// caller-supplied `avio_alloc_context` invocation. Shared by the
+/// reader/writer helpers below, which can't be collapsed into one generic
+/// function: each references a different, differently-bounded set of
+/// `extern "C"` trampolines, and referencing e.g. `seek::<T>` requires
+/// `T: Seek` at the reference site even if the call is conditional.
+unsafe fn alloc_context<T>(
+	value: T,
+	build: impl FnOnce(*mut u8, *mut c_void) -> *mut AVIOContext,
+) -> Result<(*mut AVIOContext, *mut T), Error> {
+	let opaque = Box::into_raw(Box::new(value));
+	let buffer = av_malloc(BUFFER_SIZE) as *mut u8;
+
+	if buffer.is_null() {
+		drop(Box::from_raw(opaque));
+		return Err(Error::from(AVERROR(ENOMEM)));
+	}
+
+	let context = build(buffer, opaque as *mut c_void);
+
+	if context.is_null() {
+		av_free(buffer as *mut c_void);
+		drop(Box::from_raw(opaque));
+		return Err(Error::from(AVERROR(ENOMEM)));
+	}
+
+	Ok((context, opaque))
+}
+
+unsafe fn alloc_reader<R: Read + Seek>(reader: R) -> Result<(*mut AVIOContext, *mut R), Error> {
+	alloc_context(reader, |buffer, opaque| {
+		avio_alloc_context(
+			buffer,
+			BUFFER_SIZE as c_int,
+			0,
+			opaque,
+			Some(read_packet::<R>),
+			None,
+			Some(seek::<R>),
+		)
+	})
+}
+
+unsafe fn alloc_writer<W: Write + Seek>(writer: W) -> Result<(*mut AVIOContext, *mut W), Error> {
+	alloc_context(writer, |buffer, opaque| {
+		avio_alloc_context(
+			buffer,
+			BUFFER_SIZE as c_int,
+			1,
+			opaque,
+			None,
+			Some(write_packet::<W>),
+			Some(seek::<W>),
+		)
+	})
+}
+
+/// Like [`alloc_reader`], but for a `reader` that only implements [`Read`]:
+/// no `seek` trampoline is registered, which is what makes FFmpeg treat the
+/// resulting `pb` as unseekable.
+unsafe fn alloc_unseekable_reader<R: Read>(reader: R) -> Result<(*mut AVIOContext, *mut R), Error> {
+	alloc_context(reader, |buffer, opaque| {
+		avio_alloc_context(buffer, BUFFER_SIZE as c_int, 0, opaque, Some(read_packet::<R>), None, None)
+	})
+}
+
+extern "C" fn read_packet<R>(opaque: *mut c_void, buf: *mut u8, buf_size: c_int) -> c_int
+where
+	R: Read,
+{
+	unsafe {
+		let reader = &mut *(opaque as *mut R);
+		let slice = std::slice::from_raw_parts_mut(buf, buf_size as usize);
+
+		match reader.read(slice) {
+			Ok(0) => AVERROR_EOF,
+			Ok(n) => n as c_int,
+			Err(_) => AVERROR(EIO),
+		}
+	}
+}
+
+extern "C" fn write_packet<W>(opaque: *mut c_void, buf: *mut u8, buf_size: c_int) -> c_int
+where
+	W: Write,
+{
+	unsafe {
+		let writer = &mut *(opaque as *mut W);
+		let slice = std::slice::from_raw_parts(buf, buf_size as usize);
+
+		match writer.write_all(slice) {
+			Ok(()) => buf_size,
+			Err(_) => AVERROR(EIO),
+		}
+	}
+}
+
+extern "C" fn seek<S>(opaque: *mut c_void, offset: i64, whence: c_int) -> i64
+where
+	S: Seek,
+{
+	unsafe {
+		let seeker = &mut *(opaque as *mut S);
+
+		if whence & AVSEEK_SIZE != 0 {
+			// `AVSEEK_SIZE` must return the filesize without seeking
+			// anywhere from the caller's point of view, so save and
+			// restore the position around the `End`-seek used to learn it.
+			return match seeker.stream_position().and_then(|position| {
+				let size = seeker.seek(SeekFrom::End(0))?;
+				seeker.seek(SeekFrom::Start(position))?;
+				Ok(size)
+			}) {
+				Ok(size) => size as i64,
+				Err(_) => AVERROR(EIO) as i64,
+			};
+		}
+
+		let from = match whence & !AVSEEK_SIZE {
+			0 => SeekFrom::Start(offset as u64),
+			1 => SeekFrom::Current(offset),
+			2 => SeekFrom::End(offset),
+			_ => return AVERROR(EINVAL) as i64,
+		};
+
+		match seeker.seek(from) {
+			Ok(pos) => pos as i64,
+			Err(_) => AVERROR(EIO) as i64,
+		}
+	}
+}
+
+/// An input whose packets are pulled through a custom [`Read`] + [`Seek`]
+/// implementation rather than from a filesystem path.
+pub struct Input<R> {
+	input: context::Input,
+	_io: AVIO<R>,
+}
+
+impl<R> Deref for Input<R> {
+	type Target = context::Input;
+
+	fn deref(&self) -> &context::Input {
+		&self.input
+	}
+}
+
+impl<R> DerefMut for Input<R> {
+	fn deref_mut(&mut self) -> &mut context::Input {
+		&mut self.input
+	}
+}
+
+/// An output whose packets are pushed through a custom [`Write`] + [`Seek`]
+/// implementation rather than to a filesystem path.
+pub struct Output<W> {
+	output: context::Output,
+	_io: AVIO<W>,
+}
+
+impl<W> Deref for Output<W> {
+	type Target = context::Output;
+
+	fn deref(&self) -> &context::Output {
+		&self.output
+	}
+}
+
+impl<W> DerefMut for Output<W> {
+	fn deref_mut(&mut self) -> &mut context::Output {
+		&mut self.output
+	}
+}
+
+/// Opens a demuxer input backed by an in-memory buffer, socket, or any other
+/// `reader` that implements [`Read`] and [`Seek`], instead of a filesystem path.
+pub fn input<R: Read + Seek>(reader: R) -> Result<Input<R>, Error> {
+	unsafe {
+		let (pb, opaque) = alloc_reader(reader)?;
+		let io = AVIO { context: pb, opaque };
+
+		let mut ctx = avformat_alloc_context();
+		if ctx.is_null() {
+			return Err(Error::from(AVERROR(ENOMEM)));
+		}
+
+		(*ctx).pb = io.context;
+		(*ctx).flags |= AVFMT_FLAG_CUSTOM_IO;
+
+		match avformat_open_input(&mut ctx, ptr::null(), ptr::null_mut(), ptr::null_mut()) {
+			0 => match avformat_find_stream_info(ctx, ptr::null_mut()) {
+				r if r >= 0 => Ok(Input { input: context::Input::wrap(ctx), _io: io }),
+				e => {
+					avformat_close_input(&mut ctx);
+					Err(Error::from(e))
+				}
+			},
+
+			// On failure `avformat_open_input` has already freed `ctx`; since
+			// `AVFMT_FLAG_CUSTOM_IO` is set it leaves our `pb` untouched, so
+			// `io`'s `Drop` is left to reclaim the AVIO buffer and the reader.
+			e => Err(Error::from(e)),
+		}
+	}
+}
+
+/// Like [`input`], but with the container format already known (e.g. from
+/// [`format::probe`](crate::format::probe)), so FFmpeg doesn't need to read
+/// ahead and guess on its own. `reader` still needs to be [`Seek`] — use
+/// [`input_unseekable`] for sources that genuinely can't rewind.
+pub fn input_with_format<R: Read + Seek>(
+	reader: R,
+	format: &crate::format::Input,
+) -> Result<Input<R>, Error> {
+	unsafe {
+		let (pb, opaque) = alloc_reader(reader)?;
+		let io = AVIO { context: pb, opaque };
+
+		let mut ctx = avformat_alloc_context();
+		if ctx.is_null() {
+			return Err(Error::from(AVERROR(ENOMEM)));
+		}
+
+		(*ctx).pb = io.context;
+		(*ctx).flags |= AVFMT_FLAG_CUSTOM_IO;
+
+		match avformat_open_input(&mut ctx, ptr::null(), format.as_ptr() as *mut _, ptr::null_mut()) {
+			0 => match avformat_find_stream_info(ctx, ptr::null_mut()) {
+				r if r >= 0 => Ok(Input { input: context::Input::wrap(ctx), _io: io }),
+				e => {
+					avformat_close_input(&mut ctx);
+					Err(Error::from(e))
+				}
+			},
+
+			e => Err(Error::from(e)),
+		}
+	}
+}
+
+/// Opens a demuxer input over a genuinely non-seekable `reader` (an HTTP
+/// chunk, a pipe) using a `format` already identified by
+/// [`format::probe`](crate::format::probe). `probed` must be the exact bytes
+/// already consumed from `reader` for that probe — they're spliced back in
+/// front of `reader` via [`Read::chain`], and no `seek` callback is
+/// registered on the underlying `AVIOContext`, so FFmpeg never attempts to
+/// rewind past them.
+pub fn input_unseekable<R: Read>(
+	probed: Vec<u8>,
+	reader: R,
+	format: &crate::format::Input,
+) -> Result<Input<Chain<Cursor<Vec<u8>>, R>>, Error> {
+	unsafe {
+		let (pb, opaque) = alloc_unseekable_reader(Cursor::new(probed).chain(reader))?;
+		let io = AVIO { context: pb, opaque };
+
+		let mut ctx = avformat_alloc_context();
+		if ctx.is_null() {
+			return Err(Error::from(AVERROR(ENOMEM)));
+		}
+
+		(*ctx).pb = io.context;
+		(*ctx).flags |= AVFMT_FLAG_CUSTOM_IO;
+
+		match avformat_open_input(&mut ctx, ptr::null(), format.as_ptr() as *mut _, ptr::null_mut()) {
+			0 => match avformat_find_stream_info(ctx, ptr::null_mut()) {
+				r if r >= 0 => Ok(Input { input: context::Input::wrap(ctx), _io: io }),
+				e => {
+					avformat_close_input(&mut ctx);
+					Err(Error::from(e))
+				}
+			},
+
+			e => Err(Error::from(e)),
+		}
+	}
+}
+
+/// Opens a muxer output that writes through a custom `writer` implementing
+/// [`Write`] and [`Seek`], instead of a filesystem path.
+pub fn output<W: Write + Seek>(writer: W, format: &str) -> Result<Output<W>, Error> {
+	unsafe {
+		let (pb, opaque) = alloc_writer(writer)?;
+		let io = AVIO { context: pb, opaque };
+
+		let mut ctx = ptr::null_mut();
+		let format_name = std::ffi::CString::new(format).unwrap();
+
+		match avformat_alloc_output_context2(&mut ctx, ptr::null_mut(), format_name.as_ptr(), ptr::null()) {
+			0 => {
+				(*ctx).pb = io.context;
+				(*ctx).flags |= AVFMT_FLAG_CUSTOM_IO;
+
+				Ok(Output { output: context::Output::wrap(ctx), _io: io })
+			}
+
+			e => Err(Error::from(e)),
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn round_trips_bytes_through_writer_and_reader() {
+		unsafe {
+			let (pb, opaque) = alloc_writer(Cursor::new(Vec::<u8>::new())).unwrap();
+			let io = AVIO { context: pb, opaque };
+
+			let payload = b"custom avio round trip";
+			avio_write(io.context, payload.as_ptr(), payload.len() as c_int);
+			avio_flush(io.context);
+
+			let written = (*io.opaque).get_ref().clone();
+			drop(io);
+			assert_eq!(written, payload);
+
+			let (pb, opaque) = alloc_reader(Cursor::new(written)).unwrap();
+			let io = AVIO { context: pb, opaque };
+
+			let mut buf = vec![0u8; payload.len()];
+			let n = avio_read(io.context, buf.as_mut_ptr(), buf.len() as c_int);
+
+			assert_eq!(n as usize, payload.len());
+			assert_eq!(&buf[..], &payload[..]);
+		}
+	}
+
+	#[test]
+	fn avio_size_does_not_move_the_stream_position() {
+		unsafe {
+			let mut reader = Cursor::new(b"0123456789".to_vec());
+			reader.set_position(4);
+
+			let (pb, opaque) = alloc_reader(reader).unwrap();
+			let io = AVIO { context: pb, opaque };
+
+			let size = avio_size(io.context);
+
+			assert_eq!(size, 10);
+			assert_eq!(
+				(*io.opaque).position(),
+				4,
+				"AVSEEK_SIZE must not move the stream position"
+			);
+		}
+	}
+
+	#[test]
+	fn input_unseekable_splices_probed_bytes_back_in_front_of_the_reader() {
+		let probed = b"probed-prefix".to_vec();
+		let rest: &[u8] = b"rest-of-the-stream";
+
+		// Drives the same unseekable AVIO reader `input_unseekable` builds,
+		// without needing a real demuxer to open on top of it.
+		unsafe {
+			let (pb, opaque) = alloc_unseekable_reader(Cursor::new(probed.clone()).chain(rest)).unwrap();
+			let io = AVIO { context: pb, opaque };
+
+			let mut expected = probed;
+			expected.extend_from_slice(rest);
+
+			let mut out = vec![0u8; expected.len()];
+			let n = avio_read(io.context, out.as_mut_ptr(), out.len() as c_int);
+
+			assert_eq!(n as usize, expected.len());
+			assert_eq!(out, expected);
+		}
+	}
+}
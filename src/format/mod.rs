@@ -275,6 +275,62 @@ pub fn output_as<P: AsRef<Path>>(path: P, format: &str) -> Result<context::Outpu
 	}
 }
 
+/// Sniffs the container format of `buffer` without touching disk, returning
+/// the detected input format together with FFmpeg's confidence score (out of
+/// `AVPROBE_SCORE_MAX`). `filename_hint` lets extension-based heuristics (e.g.
+/// disambiguating raw streams) weigh in even though there's no real path.
+///
+/// Pair this with [`io::input_unseekable`] to demux non-seekable sources (an
+/// HTTP chunk, a pipe) where FFmpeg has no opportunity to rewind and guess
+/// for itself — pass the same `buffer` back in as the consumed prefix.
+pub fn probe(buffer: &[u8], filename_hint: Option<&str>) -> Option<(Input, i32)> {
+	unsafe {
+		let mut padded = pad_probe_buffer(buffer);
+
+		let filename = filename_hint.map(|hint| CString::new(hint).unwrap());
+
+		let mut data = AVProbeData {
+			buf: padded.as_mut_ptr(),
+			buf_size: buffer.len() as i32,
+			filename: filename.as_ref().map_or(ptr::null(), |hint| hint.as_ptr()),
+			mime_type: ptr::null(),
+		};
+
+		let mut score = 0;
+		let detected = av_probe_input_format3(&mut data, 1, &mut score);
+
+		if detected.is_null() {
+			None
+		} else {
+			Some((Input::wrap(detected as *mut _), score))
+		}
+	}
+}
+
+/// Pads `buffer` with `AVPROBE_PADDING_SIZE` trailing zero bytes, split out
+/// of [`probe`] so the padding math can be unit-tested without calling into
+/// libav.
+fn pad_probe_buffer(buffer: &[u8]) -> Vec<u8> {
+	let mut padded = buffer.to_vec();
+	padded.resize(buffer.len() + AVPROBE_PADDING_SIZE as usize, 0);
+	padded
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn pads_probe_buffer_with_zeroed_padding() {
+		let buffer = b"probe me";
+		let padded = pad_probe_buffer(buffer);
+
+		assert_eq!(padded.len(), buffer.len() + AVPROBE_PADDING_SIZE as usize);
+		assert_eq!(&padded[..buffer.len()], buffer);
+		assert!(padded[buffer.len()..].iter().all(|&b| b == 0));
+	}
+}
+
 pub fn output_as_with<P: AsRef<Path>>(
 	path: P,
 	format: &str,
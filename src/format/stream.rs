@@ -0,0 +1,30 @@
+use crate::ffi::*;
+
+/// A single stream (track) within an opened [`format::context::Input`] or
+/// [`format::context::Output`].
+///
+/// [`format::context::Input`]: crate::format::context::Input
+/// [`format::context::Output`]: crate::format::context::Output
+pub struct Stream<'a> {
+	context: *mut AVFormatContext,
+	index: usize,
+	_marker: std::marker::PhantomData<&'a AVFormatContext>,
+}
+
+impl<'a> Stream<'a> {
+	pub unsafe fn wrap(context: *mut AVFormatContext, index: usize) -> Self {
+		Stream { context, index, _marker: std::marker::PhantomData }
+	}
+
+	pub unsafe fn as_ptr(&self) -> *const AVStream {
+		*(*self.context).streams.add(self.index)
+	}
+
+	pub fn index(&self) -> usize {
+		self.index
+	}
+
+	pub fn id(&self) -> i32 {
+		unsafe { (*self.as_ptr()).id }
+	}
+}